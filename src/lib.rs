@@ -62,6 +62,7 @@ struct LanguageIdentifierRule {
     pub language: SubTagRule,
     pub script: Option<SubTagRule>,
     pub region: Option<SubTagRule>,
+    pub variants: Vec<SubTagRule>,
 }
 
 impl From<&'_ str> for LanguageIdentifierRule {
@@ -70,10 +71,12 @@ impl From<&'_ str> for LanguageIdentifierRule {
         let language = parts.next().unwrap().into();
         let script = parts.next().map(|s| s.into());
         let region = parts.next().map(|s| s.into());
+        let variants = parts.map(|s| s.into()).collect();
         Self {
             language,
             script,
             region,
+            variants,
         }
     }
 }
@@ -95,6 +98,13 @@ impl Rule<&'_ LanguageIdentifier> for &'_ LanguageIdentifierRule {
                 .region
                 .as_ref()
                 .matches(lang.region.as_ref().map(|s| s.as_str()), vars)
+            && (self.variants.is_empty()
+                || self.variants.len() == lang.variants.len()
+                    && self
+                        .variants
+                        .iter()
+                        .zip(lang.variants.iter())
+                        .all(|(r, v)| r.matches(v.as_str(), vars)))
     }
 }
 
@@ -144,11 +154,130 @@ struct SupplementalData {
     pub language_matching: LanguageMatching,
 }
 
+#[derive(Debug, Deserialize, PartialEq)]
+struct ContainmentGroup {
+    #[serde(rename = "@type")]
+    pub ty: String,
+    #[serde(rename = "@contains")]
+    pub contains: String,
+    #[serde(default, rename = "@status")]
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct TerritoryContainment {
+    pub group: Vec<ContainmentGroup>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct SupplementalContainment {
+    pub territory_containment: TerritoryContainment,
+}
+
 const LANGUAGE_INFO: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
     "/data/languageInfo.xml"
 ));
 
+const SUPPLEMENTAL_DATA: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/data/supplementalData.xml"
+));
+
+/// Compute, for every territory, the set of macro-regions (`001` world, `419`
+/// Latin America, `150` Europe, …) that transitively contain it, from the raw
+/// `<territoryContainment>` groups. Canonical groups and groupings (such as
+/// `419`) are followed; deprecated groups are not.
+fn territory_ancestors(groups: &[ContainmentGroup]) -> HashMap<String, HashSet<String>> {
+    let mut children: HashMap<&str, Vec<&str>> = HashMap::new();
+    for g in groups
+        .iter()
+        .filter(|g| g.status.as_deref() != Some("deprecated"))
+    {
+        children
+            .entry(g.ty.as_str())
+            .or_default()
+            .extend(g.contains.split(' '));
+    }
+
+    let mut ancestors: HashMap<String, HashSet<String>> = HashMap::new();
+    for &group in children.keys() {
+        let mut stack = vec![group];
+        let mut seen = HashSet::new();
+        while let Some(code) = stack.pop() {
+            if let Some(kids) = children.get(code) {
+                for &child in kids {
+                    ancestors
+                        .entry(child.to_string())
+                        .or_default()
+                        .insert(group.to_string());
+                    if seen.insert(child) {
+                        stack.push(child);
+                    }
+                }
+            }
+        }
+    }
+    ancestors
+}
+
+/// Distance at or above which a match is considered unusable. Because this
+/// crate scales CLDR distances by ten, this is the raw `100` no-match threshold
+/// of the algorithm.
+const NO_MATCH_THRESHOLD: u16 = 1000;
+
+/// Penalty added when two tags agree on language, script, and region but carry
+/// different variant subtags (e.g. `de-1996` vs `de-1901`). It is deliberately
+/// smaller than any ×10 region change, so orthography/dialect variants rank
+/// just behind an exact match but ahead of a region switch.
+const VARIANT_DISTANCE: u16 = 1;
+
+/// Distance at or above which a match has likely changed script rather than
+/// merely region, scaled by ten like every other distance in this crate.
+const SCRIPT_CHANGE_THRESHOLD: u16 = 100;
+
+/// How much one unit of lost `q` weight is worth in distance units when
+/// matching an `Accept-Language` header. A full quality drop (`q=0`, which is
+/// itself discarded) would cost `ACCEPT_LANGUAGE_SCALE`, so a strongly
+/// preferred but slightly worse language can outrank a weakly preferred exact
+/// one.
+const ACCEPT_LANGUAGE_SCALE: f32 = 100.0;
+
+/// Parse a raw HTTP `Accept-Language` header into language ranges sorted by
+/// descending quality. A range of `*` is represented as `None` (any language).
+/// Malformed entries and entries with `q=0` are dropped.
+fn parse_accept_language(header: &str) -> Vec<(Option<LanguageIdentifier>, f32)> {
+    let mut ranges = header
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';');
+            let range = parts.next()?.trim();
+            if range.is_empty() {
+                return None;
+            }
+            let mut q = 1.0f32;
+            for param in parts {
+                if let Some(value) = param.trim().strip_prefix("q=") {
+                    q = value.trim().parse().ok()?;
+                }
+            }
+            if !(0.0..=1.0).contains(&q) || q == 0.0 {
+                return None;
+            }
+            let lang = if range == "*" {
+                None
+            } else {
+                Some(range.parse().ok()?)
+            };
+            Some((lang, q))
+        })
+        .collect::<Vec<_>>();
+    ranges.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap());
+    ranges
+}
+
 /// This is a language matcher.
 /// The distance of two languages are calculated by the algorithm of [CLDR].
 /// The value of distance is multiplied by 10, because we need to consider the paradigm locales.
@@ -189,10 +318,104 @@ pub struct LanguageMatcher {
     vars: Variables,
     rules: Vec<LanguageMatch>,
     expander: LocaleExpander,
+    prefer_same_script: bool,
+    containment: HashMap<String, HashSet<String>>,
+}
+
+/// Builder for [`LanguageMatcher`], exposing configuration knobs like the
+/// `MatchOption`s of Go's matcher. Use [`LanguageMatcher::new`] for the
+/// defaults.
+#[derive(Debug, Default)]
+pub struct LanguageMatcherBuilder {
+    prefer_same_script: bool,
+}
+
+impl LanguageMatcherBuilder {
+    /// Creates a builder with the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// When enabled and no close match exists, bias selection toward supported
+    /// tags whose maximized script equals the desired tag's maximized script,
+    /// rather than falling back to an unrelated script.
+    pub fn prefer_same_script(mut self, value: bool) -> Self {
+        self.prefer_same_script = value;
+        self
+    }
+
+    /// Builds the [`LanguageMatcher`] from the bundled CLDR data.
+    pub fn build(self) -> LanguageMatcher {
+        LanguageMatcher {
+            prefer_same_script: self.prefer_same_script,
+            ..LanguageMatcher::new()
+        }
+    }
 }
 
 type Variables = HashMap<String, HashSet<String>>;
 
+/// An error that may occur while constructing a [`LanguageMatcher`] from CLDR
+/// data.
+#[derive(Debug)]
+pub enum Error {
+    /// The XML data could not be deserialized.
+    Parse(quick_xml::DeError),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse(e) => write!(f, "failed to parse CLDR data: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<quick_xml::DeError> for Error {
+    fn from(e: quick_xml::DeError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+/// The confidence of a language match, mirroring the levels of Go's matcher.
+///
+/// Confidence is derived from the distance bands this crate uses, so callers
+/// can decide whether to use a match or fall back to their own default locale
+/// without hardcoding numbers that depend on the ×10 scaling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Confidence {
+    /// No usable match; the caller should fall back to its default locale.
+    No,
+    /// A distant match, typically differing in script.
+    Low,
+    /// A close match, differing only in minor ways such as region.
+    High,
+    /// An exact match.
+    Exact,
+}
+
+impl Confidence {
+    fn from_distance(distance: u16) -> Self {
+        if distance == 0 {
+            Self::Exact
+        } else if distance < SCRIPT_CHANGE_THRESHOLD {
+            Self::High
+        } else if distance < NO_MATCH_THRESHOLD {
+            Self::Low
+        } else {
+            Self::No
+        }
+    }
+}
+
 impl From<SupplementalData> for LanguageMatcher {
     fn from(data: SupplementalData) -> Self {
         let expander = LocaleExpander::new_extended();
@@ -226,15 +449,53 @@ impl From<SupplementalData> for LanguageMatcher {
             vars,
             rules: matches.language_match,
             expander,
+            prefer_same_script: false,
+            containment: HashMap::new(),
         }
     }
 }
 
 impl LanguageMatcher {
-    /// Creates an instance of [`LanguageMatcher`].
+    /// Creates an instance of [`LanguageMatcher`] from the bundled CLDR data.
+    ///
+    /// Panics if the bundled data cannot be parsed, which should never happen.
+    /// Use [`try_new`](Self::try_new) to handle the error instead.
     pub fn new() -> Self {
-        let data: SupplementalData = quick_xml::de::from_str(LANGUAGE_INFO).unwrap();
-        data.into()
+        Self::try_new().unwrap()
+    }
+
+    /// Creates an instance of [`LanguageMatcher`] from the bundled CLDR data,
+    /// returning an error if that data cannot be parsed.
+    pub fn try_new() -> Result<Self, Error> {
+        Self::from_xml_with_containment(LANGUAGE_INFO, SUPPLEMENTAL_DATA)
+    }
+
+    /// Creates an instance of [`LanguageMatcher`] from caller-supplied
+    /// `languageInfo.xml` contents, so applications tracking newer CLDR releases
+    /// can feed updated data at runtime without recompiling.
+    ///
+    /// The resulting matcher has no territory-containment data, so the
+    /// region-fallback behavior is disabled; use [`from_xml_with_containment`]
+    /// to supply `supplementalData.xml` as well.
+    ///
+    /// [`from_xml_with_containment`]: Self::from_xml_with_containment
+    pub fn from_xml(xml: &str) -> Result<Self, Error> {
+        let data: SupplementalData = quick_xml::de::from_str(xml)?;
+        Ok(data.into())
+    }
+
+    /// Creates an instance of [`LanguageMatcher`] from caller-supplied
+    /// `languageInfo.xml` and `supplementalData.xml` contents, keeping the
+    /// territory-containment region fallback when tracking newer CLDR releases.
+    pub fn from_xml_with_containment(
+        language_info: &str,
+        supplemental_data: &str,
+    ) -> Result<Self, Error> {
+        let containment: SupplementalContainment = quick_xml::de::from_str(supplemental_data)?;
+        Ok(LanguageMatcher {
+            containment: territory_ancestors(&containment.territory_containment.group),
+            ..Self::from_xml(language_info)?
+        })
     }
 
     /// Choose the nearst language of desired language from the supported language collection.
@@ -253,10 +514,110 @@ impl LanguageMatcher {
             .map(|s| {
                 let mut max_s = s.clone();
                 self.expander.maximize(&mut max_s);
-                (s, self.distance_impl(desired.clone(), max_s))
+                let script_differs = self.prefer_same_script && max_s.script != desired.script;
+                (s, self.distance_impl(desired.clone(), max_s), script_differs)
+            })
+            .min_by_key(|(_, dis, script_differs)| Self::script_sort_key(*dis, *script_differs))
+            .filter(|(_, dis, _)| *dis < NO_MATCH_THRESHOLD)
+            .map(|(s, dis, _)| (s, dis))
+    }
+
+    /// Like [`matches`](Self::matches), but always reports the nearst supported
+    /// language together with its raw distance and a [`Confidence`] level.
+    ///
+    /// Nothing is filtered out, so the confidence may be [`Confidence::No`];
+    /// this lets callers decide "use this" versus "fall back to my default
+    /// locale" without comparing against raw distances. `None` is returned only
+    /// when `supported` is empty.
+    pub fn matches_with_confidence<'a>(
+        &self,
+        mut desired: LanguageIdentifier,
+        supported: impl IntoIterator<Item = &'a LanguageIdentifier>,
+    ) -> Option<(&'a LanguageIdentifier, u16, Confidence)> {
+        self.expander.maximize(&mut desired);
+        supported
+            .into_iter()
+            .map(|s| {
+                let mut max_s = s.clone();
+                self.expander.maximize(&mut max_s);
+                let script_differs = self.prefer_same_script && max_s.script != desired.script;
+                (s, self.distance_impl(desired.clone(), max_s), script_differs)
             })
-            .min_by_key(|(_, dis)| *dis)
-            .filter(|(_, dis)| *dis < 1000)
+            .min_by_key(|(_, dis, script_differs)| Self::script_sort_key(*dis, *script_differs))
+            .map(|(s, dis, _)| (s, dis, Confidence::from_distance(dis)))
+    }
+
+    /// Sort key implementing the `prefer_same_script` bias. Close matches are
+    /// ordered purely by distance; only when no close match exists does a
+    /// different-script candidate get demoted below same-script ones, so the
+    /// option never overrides a genuinely good match.
+    fn script_sort_key(distance: u16, script_differs: bool) -> (bool, u16) {
+        (script_differs && distance >= SCRIPT_CHANGE_THRESHOLD, distance)
+    }
+
+    /// Choose the nearst supported language for a raw HTTP `Accept-Language`
+    /// header, the way Go's `ParseAcceptLanguage`/`MatchStrings` do.
+    ///
+    /// The header is split on commas; each entry is a language range plus an
+    /// optional `;q=` quality factor in `[0.0, 1.0]` (default `1.0`). Malformed
+    /// entries and entries with `q=0` are dropped, and `*` means "any". Ranges
+    /// are tried in descending order of quality, and the comparison is biased by
+    /// quality so a strongly preferred but slightly worse language can win over a
+    /// weakly preferred exact one. Returns the chosen supported tag and its raw
+    /// distance.
+    ///
+    /// If only a `*` range matches, the first supported tag is returned as a
+    /// fallback with a reported distance of `1000`; the distance is meaningless
+    /// for a wildcard match and should not be read as a perfect one.
+    pub fn matches_accept_language<'a>(
+        &self,
+        header: &str,
+        supported: impl IntoIterator<Item = &'a LanguageIdentifier>,
+    ) -> Option<(&'a LanguageIdentifier, u16)> {
+        let supported = supported
+            .into_iter()
+            .map(|s| {
+                let mut max_s = s.clone();
+                self.expander.maximize(&mut max_s);
+                (s, max_s)
+            })
+            .collect::<Vec<_>>();
+
+        // (supported tag, raw distance, quality-biased distance)
+        let mut best: Option<(&LanguageIdentifier, u16, u16)> = None;
+        // Whether any `*` range was seen, used only if no concrete range matches.
+        let mut wildcard = false;
+        for (range, q) in parse_accept_language(header) {
+            let bias = ((1.0 - q) * ACCEPT_LANGUAGE_SCALE) as u16;
+            match range {
+                // `*` accepts any supported language, but only as a true last
+                // resort: it never competes against concrete ranges.
+                None => wildcard = true,
+                Some(mut desired) => {
+                    self.expander.maximize(&mut desired);
+                    for (s, max_s) in &supported {
+                        let raw = self.distance_impl(desired.clone(), max_s.clone());
+                        if raw >= NO_MATCH_THRESHOLD {
+                            continue;
+                        }
+                        let biased = raw.saturating_add(bias);
+                        if best.is_none_or(|(_, _, b)| biased < b) {
+                            best = Some((s, raw, biased));
+                        }
+                    }
+                }
+            }
+        }
+        best.map(|(s, raw, _)| (s, raw)).or_else(|| {
+            // No concrete range matched; fall back to the first supported tag
+            // if the header allowed any language. The reported distance is
+            // `NO_MATCH_THRESHOLD`, since a wildcard fallback is not a real
+            // (let alone perfect) match — see the method docs.
+            wildcard
+                .then(|| supported.first())
+                .flatten()
+                .map(|(s, _)| (*s, NO_MATCH_THRESHOLD))
+        })
     }
 
     /// Calculate the distance of the two language.
@@ -286,8 +647,20 @@ impl LanguageMatcher {
 
         let mut distance = 0;
 
+        let same_base = desired.language == supported.language
+            && desired.script == supported.script
+            && desired.region == supported.region;
+        let variants_differ = desired.variants != supported.variants;
+
         if desired.region != supported.region {
-            distance += self.distance_match(&desired, &supported);
+            let mut region_distance = self.distance_match(&desired, &supported);
+            // A more-specific region transitively inside the other, enclosing
+            // region (e.g. `MX` within `419`) is closer than an unrelated
+            // region of the same nominal distance.
+            if region_distance > 0 && self.region_contained(&desired, &supported) {
+                region_distance -= 1;
+            }
+            distance += region_distance;
         }
         desired.region = None;
         supported.region = None;
@@ -302,6 +675,10 @@ impl LanguageMatcher {
             distance += self.distance_match(&desired, &supported);
         }
 
+        if same_base && variants_differ {
+            distance += VARIANT_DISTANCE;
+        }
+
         distance
     }
 
@@ -324,6 +701,23 @@ impl LanguageMatcher {
         unreachable!()
     }
 
+    /// Whether one of the two tags' regions is transitively contained within
+    /// the other's region (e.g. `MX` within `419`). Sibling regions that only
+    /// share an enclosing group are not contained in one another.
+    fn region_contained(&self, desired: &LanguageIdentifier, supported: &LanguageIdentifier) -> bool {
+        let (Some(d), Some(s)) = (desired.region.as_ref(), supported.region.as_ref()) else {
+            return false;
+        };
+        let (d, s) = (d.as_str(), s.as_str());
+        self.containment
+            .get(d)
+            .is_some_and(|groups| groups.contains(s))
+            || self
+                .containment
+                .get(s)
+                .is_some_and(|groups| groups.contains(d))
+    }
+
     fn is_paradigm(&self, lang: &LanguageIdentifier) -> bool {
         self.paradigm.contains(lang)
     }
@@ -337,7 +731,7 @@ impl Default for LanguageMatcher {
 
 #[cfg(test)]
 mod test {
-    use crate::LanguageMatcher;
+    use crate::{Confidence, Error, LanguageMatcher, LanguageMatcherBuilder};
     use icu_locale::langid;
 
     #[test]
@@ -369,4 +763,127 @@ mod test {
             Some((&langid!("zh-Hant"), 0))
         );
     }
+
+    #[test]
+    fn confidence() {
+        let matcher = LanguageMatcher::new();
+
+        let accepts = [langid!("en"), langid!("ja"), langid!("zh-Hans")];
+        assert_eq!(
+            matcher.matches_with_confidence(langid!("zh-CN"), &accepts),
+            Some((&langid!("zh-Hans"), 0, Confidence::Exact))
+        );
+        assert_eq!(
+            matcher.matches_with_confidence(langid!("en-GB"), &accepts),
+            Some((&langid!("en"), 50, Confidence::High))
+        );
+    }
+
+    #[test]
+    fn accept_language() {
+        let matcher = LanguageMatcher::new();
+
+        let accepts = [langid!("en"), langid!("ja"), langid!("zh-Hans")];
+        assert_eq!(
+            matcher.matches_accept_language("zh-CN, en;q=0.8", &accepts),
+            Some((&langid!("zh-Hans"), 0))
+        );
+        // A malformed entry and a `q=0` entry are skipped, leaving only `ja`.
+        assert_eq!(
+            matcher.matches_accept_language("de;q=bad, en;q=0, ja", &accepts),
+            Some((&langid!("ja"), 0))
+        );
+
+        // A lone `*` falls back to the first supported tag, reported as a
+        // non-match distance rather than a perfect one.
+        assert_eq!(
+            matcher.matches_accept_language("*", &accepts),
+            Some((&langid!("en"), 1000))
+        );
+        // A concrete range always wins over `*`, regardless of ordering or
+        // quality: `*` is only a last resort.
+        assert_eq!(
+            matcher.matches_accept_language("*, en", &[langid!("en")]),
+            Some((&langid!("en"), 0))
+        );
+        assert_eq!(
+            matcher.matches_accept_language("en;q=0.1, *", &accepts),
+            Some((&langid!("en"), 0))
+        );
+    }
+
+    #[test]
+    fn variant() {
+        let matcher = LanguageMatcher::new();
+
+        // Orthography variants cost a small fixed penalty, ...
+        assert_eq!(matcher.distance(langid!("de-1996"), langid!("de-1901")), 1);
+        // ... which ranks strictly below an exact match but strictly above a
+        // region switch.
+        assert!(
+            matcher.distance(langid!("de-1996"), langid!("de-1901"))
+                < matcher.distance(langid!("de"), langid!("de-CH"))
+        );
+        // A rule with no variant constraint still matches a tag carrying a
+        // variant, so adding a variant leaves an unrelated region switch
+        // unchanged.
+        assert_eq!(
+            matcher.distance(langid!("de-1996"), langid!("de-CH")),
+            matcher.distance(langid!("de"), langid!("de-CH"))
+        );
+    }
+
+    #[test]
+    fn containment() {
+        let matcher = LanguageMatcher::new();
+
+        // es-MX falls inside the es-419 (Latin America) macro-region, so it is
+        // preferred over an unrelated region at the same nominal distance.
+        let accepts = [langid!("es-ES"), langid!("es-419")];
+        assert_eq!(
+            matcher.matches(langid!("es-MX"), &accepts).map(|(l, _)| l),
+            Some(&langid!("es-419"))
+        );
+
+        assert!(matcher.region_contained(&langid!("es-MX"), &langid!("es-419")));
+        // Sibling regions are not contained in one another and must not be
+        // drawn artificially closer.
+        assert!(!matcher.region_contained(&langid!("en-US"), &langid!("en-CA")));
+    }
+
+    #[test]
+    fn prefer_same_script() {
+        let default = LanguageMatcher::new();
+        let same_script = LanguageMatcherBuilder::new().prefer_same_script(true).build();
+
+        // A clear match is unaffected by the option.
+        let accepts = [langid!("en"), langid!("zh-Hans"), langid!("zh-Hant")];
+        assert_eq!(
+            default.matches(langid!("zh-CN"), &accepts),
+            same_script.matches(langid!("zh-CN"), &accepts)
+        );
+
+        // Hindi (Devanagari) is a closer language match to Urdu (Arabic) than
+        // to Marathi (Devanagari), so by raw distance the default matcher picks
+        // the different-script `ur`.
+        let scripts = [langid!("ur"), langid!("mr")];
+        assert_eq!(
+            default.matches(langid!("hi"), &scripts).map(|(l, _)| l),
+            Some(&langid!("ur"))
+        );
+        // With the option on, and no close match available, the same-script
+        // `mr` is preferred instead.
+        assert_eq!(
+            same_script.matches(langid!("hi"), &scripts).map(|(l, _)| l),
+            Some(&langid!("mr"))
+        );
+    }
+
+    #[test]
+    fn from_xml_error() {
+        assert!(matches!(
+            LanguageMatcher::from_xml("<not-valid"),
+            Err(Error::Parse(_))
+        ));
+    }
 }